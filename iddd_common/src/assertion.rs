@@ -12,11 +12,43 @@ impl Display for ArgumentError {
 
 impl Error for ArgumentError {}
 
+/// Accumulates validation failures so that a whole set of checks can be
+/// reported at once, as expected by the IDDD Notification pattern.
+#[derive(Debug, Default)]
+pub struct ValidationNotification(Vec<String>);
+
+impl ValidationNotification {
+    /// Creates a new, empty [ValidationNotification].
+    pub fn new() -> Self {
+        ValidationNotification(Vec::new())
+    }
+
+    /// Returns `true` when no failure has been collected.
+    pub fn is_valid(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds a failure message to the notification.
+    pub fn add(&mut self, message: String) {
+        self.0.push(message);
+    }
+
+    /// Consumes the notification yielding `Ok(())` when valid, or an
+    /// [ArgumentError] whose message joins every failure with `"; "`.
+    pub fn into_result(self) -> ::std::result::Result<(), ArgumentError> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(ArgumentError(self.0.join("; ")))
+        }
+    }
+}
+
 #[macro_export]
 #[allow(unused_macros)]
 macro_rules! arg_error {
     ($($arg:tt)*) => {
-        Err(ArgumentError(format!($($arg)*)))
+        Err($crate::assertion::ArgumentError(format!($($arg)*)))
     }
 }
 
@@ -24,7 +56,7 @@ macro_rules! arg_error {
 macro_rules! assert_argument_equals {
     ($actual:expr, $expected:expr, $($arg:tt)*) => {{
         if $expected != $actual {
-            return arg_error!("The {1} must be equal to {0}", $expected, $($arg)*);
+            return $crate::arg_error!("The {1} must be equal to {0}", $expected, $($arg)*);
         }
         Ok(())
     }}
@@ -34,7 +66,7 @@ macro_rules! assert_argument_equals {
 macro_rules! assert_argument_false {
     ($actual:expr, $($arg:tt)*) => {{
         if $actual {
-            return arg_error!("The {0} must be false", $($arg)*);
+            return $crate::arg_error!("The {0} must be false", $($arg)*);
         }
         Ok(())
     }}
@@ -45,15 +77,15 @@ macro_rules! assert_argument_length {
     ($expected:expr, $minimum:expr, $maximum:expr, $($arg:tt)*) => {{
         if $expected.len() < $minimum || $expected.len() > $maximum {
             if $minimum == $maximum {
-                return arg_error!("The {1} must be {0} characters long", $minimum, $($arg)*);
+                return $crate::arg_error!("The {1} must be {0} characters long", $minimum, $($arg)*);
             }
-            return arg_error!("The {2} must be long between {0} and {1} characters", $minimum, $maximum, $($arg)*);
+            return $crate::arg_error!("The {2} must be long between {0} and {1} characters", $minimum, $maximum, $($arg)*);
         }
         Ok(())
     }};
     ($expected:expr, $maximum:expr, $($arg:tt)*) => {{
         if $expected.len() > $maximum {
-            return arg_error!("The {1} must be {0} characters or less", $maximum, $($arg)*);
+            return $crate::arg_error!("The {1} must be {0} characters or less", $maximum, $($arg)*);
         }
         Ok(())
     }};
@@ -63,7 +95,7 @@ macro_rules! assert_argument_length {
 macro_rules! assert_argument_not_empty {
     ($actual:expr, $($arg:tt)*) => {{
         if $actual.is_empty() {
-            return arg_error!("The {} is required", $($arg)*);
+            return $crate::arg_error!("The {} is required", $($arg)*);
         }
         Ok(())
     }}
@@ -73,7 +105,7 @@ macro_rules! assert_argument_not_empty {
 macro_rules! assert_argument_not_equals {
     ($actual:expr, $expected:expr, $($arg:tt)*) => {{
         if $expected == $actual {
-            return arg_error!("The {1} must be different from {0}.", $actual, $($arg)*);
+            return $crate::arg_error!("The {1} must be different from {0}.", $actual, $($arg)*);
         }
         Ok(())
     }}
@@ -83,7 +115,7 @@ macro_rules! assert_argument_not_equals {
 macro_rules! assert_argument_range {
     ($expected:expr, $minimum:expr, $maximum:expr, $($arg:tt)*) => {{
         if $expected < $minimum || $expected > $maximum {
-            return arg_error!("The {2} must be between {0} and {1}.", $minimum, $maximum, $($arg)*);
+            return $crate::arg_error!("The {2} must be between {0} and {1}.", $minimum, $maximum, $($arg)*);
         }
         Ok(())
     }}
@@ -93,7 +125,7 @@ macro_rules! assert_argument_range {
 macro_rules! assert_argument_true {
     ($actual:expr, $($arg:tt)*) => {{
         if !$actual {
-            return arg_error!("The {0} must be true", $($arg)*);
+            return $crate::arg_error!("The {0} must be true", $($arg)*);
         }
         Ok(())
     }}
@@ -103,12 +135,118 @@ macro_rules! assert_argument_true {
 macro_rules! assert_true {
     ($actual:expr, $($arg:tt)*) => {{
         if !$actual {
-            return arg_error!($($arg)*);
+            return $crate::arg_error!($($arg)*);
         }
         Ok(())
     }}
 }
 
+#[macro_export]
+macro_rules! check_equals {
+    ($actual:expr, $expected:expr, $($arg:tt)*) => {
+        if $expected != $actual {
+            Some(format!("The {1} must be equal to {0}", $expected, $($arg)*))
+        } else {
+            None
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! check_false {
+    ($actual:expr, $($arg:tt)*) => {
+        if $actual {
+            Some(format!("The {0} must be false", $($arg)*))
+        } else {
+            None
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! check_length {
+    ($expected:expr, $minimum:expr, $maximum:expr, $($arg:tt)*) => {
+        if $expected.len() < $minimum || $expected.len() > $maximum {
+            if $minimum == $maximum {
+                Some(format!("The {1} must be {0} characters long", $minimum, $($arg)*))
+            } else {
+                Some(format!("The {2} must be long between {0} and {1} characters", $minimum, $maximum, $($arg)*))
+            }
+        } else {
+            None
+        }
+    };
+    ($expected:expr, $maximum:expr, $($arg:tt)*) => {
+        if $expected.len() > $maximum {
+            Some(format!("The {1} must be {0} characters or less", $maximum, $($arg)*))
+        } else {
+            None
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! check_not_empty {
+    ($actual:expr, $($arg:tt)*) => {
+        if $actual.is_empty() {
+            Some(format!("The {} is required", $($arg)*))
+        } else {
+            None
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! check_not_equals {
+    ($actual:expr, $expected:expr, $($arg:tt)*) => {
+        if $expected == $actual {
+            Some(format!("The {1} must be different from {0}.", $actual, $($arg)*))
+        } else {
+            None
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! check_range {
+    ($expected:expr, $minimum:expr, $maximum:expr, $($arg:tt)*) => {
+        if $expected < $minimum || $expected > $maximum {
+            Some(format!("The {2} must be between {0} and {1}.", $minimum, $maximum, $($arg)*))
+        } else {
+            None
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! check_true {
+    ($actual:expr, $($arg:tt)*) => {
+        if !$actual {
+            Some(format!("The {0} must be true", $($arg)*))
+        } else {
+            None
+        }
+    }
+}
+
+/// Runs a list of `check_*!` checks, collecting every failure into a single
+/// [ValidationNotification](crate::assertion::ValidationNotification) before
+/// returning. Each check yields an `Option<String>`; every `Some` is added to
+/// the notification, so the caller sees all field-level errors in one pass
+/// instead of short-circuiting on the first.
+#[macro_export]
+macro_rules! validate {
+    ( $( $check:expr ),* $(,)? ) => {{
+        let mut notification = $crate::assertion::ValidationNotification::new();
+        $(
+            if let Some(message) = $check {
+                notification.add(message);
+            }
+        )*
+        notification.into_result()
+    }}
+}
+
 
 #[derive(Debug)]
 pub struct StateError(pub String);
@@ -125,24 +263,57 @@ impl Error for StateError {}
 #[allow(unused_macros)]
 macro_rules! state_error {
     ($($arg:tt)*) => {
-        Err(StateError(format!($($arg)*)))
+        Err($crate::assertion::StateError(format!($($arg)*)))
     }
 }
 
 #[macro_export]
 macro_rules! assert_not_state {
-    ($actual:expr, $($arg:tt)*) => {
+    ($actual:expr, $($arg:tt)*) => {{
         if $actual {
-            state_error!($($arg)*);
+            return $crate::state_error!($($arg)*);
         }
-    }
+        Ok(())
+    }}
 }
 
 #[macro_export]
 macro_rules! assert_state {
-    ($actual:expr, $($arg:tt)*) => {
+    ($actual:expr, $($arg:tt)*) => {{
         if !$actual {
-            state_error!($($arg)*);
+            return $crate::state_error!($($arg)*);
         }
+        Ok(())
+    }}
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    pub fn test_validate_accumulates_every_failure() {
+        let name = "";
+        let code = "0".repeat(20);
+        let result = crate::validate! {
+            crate::check_not_empty!(name, "name"),
+            crate::check_length!(code.as_str(), 1, 10, "code"),
+        };
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(),
+                   "The name is required; The code must be long between 1 and 10 characters");
+    }
+
+    #[test]
+    pub fn test_validate_passes_when_all_checks_hold() {
+        let name = "acme";
+        let result = crate::validate!(crate::check_not_empty!(name, "name"));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    pub fn test_notification_is_valid_until_a_failure_is_added() {
+        let mut notification = crate::assertion::ValidationNotification::new();
+        assert_eq!(true, notification.is_valid());
+        notification.add("boom".to_string());
+        assert_eq!(false, notification.is_valid());
     }
 }
\ No newline at end of file
@@ -0,0 +1,159 @@
+/// Generates the standard aggregate-root shape: a struct with private fields,
+/// a validating constructor, a getter per constructor field whose visibility
+/// follows the optional leading `$vis`, and a [Display](std::fmt::Display)
+/// implementation.
+///
+/// Each constructor field is `[$vis] name: Type [rules]`, where the optional
+/// bracketed rules are applied against the named field in the generated
+/// `new(...)`:
+///
+/// * `not_empty` &rarr; `assert_argument_not_empty!`
+/// * `max_len = N` &rarr; `assert_argument_length!`
+///
+/// `Option<String>` fields are special-cased exactly as the hand-written
+/// constructors do: the constructor takes an `Option<&str>`, validation is
+/// applied only to the `Some` value, and the field is stored via
+/// `.filter(|s| !s.is_empty()).map(...)`. An optional trailing
+/// `internal { field: Type = default }` section declares fields that are not
+/// constructor parameters and carry no getter — the aggregate's own state,
+/// such as owned collections. Hand-written domain methods keep living in a
+/// separate `impl` block.
+///
+/// ```ignore
+/// entity!(Tenant {
+///     pub tenant_id: TenantId,
+///     pub name: String [not_empty, max_len = 100],
+///     pub description: Option<String> [max_len = 100],
+///     pub active: bool
+/// } internal {
+///     invitations: Vec<Invitation> = Vec::new()
+/// });
+/// ```
+#[macro_export]
+macro_rules! entity {
+    ($name:ident { $($body:tt)* }) => {
+        $crate::entity!(@ctor $name [] [] $($body)* ,);
+    };
+    ($name:ident { $($body:tt)* } internal { $($int:tt)* }) => {
+        $crate::entity!(@ctor $name [] [ $($int)* , ] $($body)* ,);
+    };
+
+    // --- constructor-field muncher: classify each type, carry a kind tag --
+    (@ctor $name:ident [ $($c:tt)* ] [ $($int:tt)* ]) => {
+        $crate::entity!(@int $name [ $($c)* ] [] $($int)*);
+    };
+    (@ctor $name:ident [ $($c:tt)* ] [ $($int:tt)* ] , $($rest:tt)*) => {
+        $crate::entity!(@ctor $name [ $($c)* ] [ $($int)* ] $($rest)*);
+    };
+    (@ctor $name:ident [ $($c:tt)* ] [ $($int:tt)* ] $vis:vis $field:ident : Option < String > $([ $($r:tt)* ])? , $($rest:tt)*) => {
+        $crate::entity!(@ctor $name [ $($c)* { $vis $field opt_string [ $($($r)*)? ] Option<String> } ] [ $($int)* ] $($rest)*);
+    };
+    (@ctor $name:ident [ $($c:tt)* ] [ $($int:tt)* ] $vis:vis $field:ident : String $([ $($r:tt)* ])? , $($rest:tt)*) => {
+        $crate::entity!(@ctor $name [ $($c)* { $vis $field string [ $($($r)*)? ] String } ] [ $($int)* ] $($rest)*);
+    };
+    (@ctor $name:ident [ $($c:tt)* ] [ $($int:tt)* ] $vis:vis $field:ident : bool $([ $($r:tt)* ])? , $($rest:tt)*) => {
+        $crate::entity!(@ctor $name [ $($c)* { $vis $field bool [ $($($r)*)? ] bool } ] [ $($int)* ] $($rest)*);
+    };
+    (@ctor $name:ident [ $($c:tt)* ] [ $($int:tt)* ] $vis:vis $field:ident : $ty:ty $([ $($r:tt)* ])? , $($rest:tt)*) => {
+        $crate::entity!(@ctor $name [ $($c)* { $vis $field other [ $($($r)*)? ] $ty } ] [ $($int)* ] $($rest)*);
+    };
+
+    // --- internal-field muncher -------------------------------------------
+    (@int $name:ident [ $($c:tt)* ] [ $($i:tt)* ]) => {
+        $crate::entity!(@emit $name [ $($c)* ] [ $($i)* ]);
+    };
+    (@int $name:ident [ $($c:tt)* ] [ $($i:tt)* ] , $($rest:tt)*) => {
+        $crate::entity!(@int $name [ $($c)* ] [ $($i)* ] $($rest)*);
+    };
+    (@int $name:ident [ $($c:tt)* ] [ $($i:tt)* ] $field:ident : $ty:ty = $default:expr , $($rest:tt)*) => {
+        $crate::entity!(@int $name [ $($c)* ] [ $($i)* { $field : $ty = $default } ] $($rest)*);
+    };
+
+    // --- final emission ---------------------------------------------------
+    (@emit $name:ident
+        [ $( { $fvis:vis $field:ident $kind:ident [ $($rule:tt)* ] $ty:ty } )* ]
+        [ $( { $ifield:ident : $ity:ty = $idefault:expr } )* ]
+    ) => {
+        #[derive(Debug)]
+        pub struct $name {
+            $( $field: $ty, )*
+            $( $ifield: $ity, )*
+        }
+
+        impl $name {
+            /// Creates a new instance, validating the supplied parameters.
+            pub fn new(
+                $( $field: $crate::entity!(@param_ty $kind $ty), )*
+            ) -> ::std::result::Result<Self, $crate::assertion::ArgumentError> {
+                $( $crate::entity!(@checks $kind $field, $($rule)*); )*
+                Ok($name {
+                    $( $field: $crate::entity!(@store $kind $field), )*
+                    $( $ifield: $idefault, )*
+                })
+            }
+
+            $(
+                $fvis fn $field(&self) -> $crate::entity!(@getter_ty $kind $ty) {
+                    $crate::entity!(@getter $kind self, $field)
+                }
+            )*
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let fields: ::std::vec::Vec<String> = ::std::vec![
+                    $( format!("{} = {:?}", stringify!($field), self.$field), )*
+                    $( format!("{} = {:?}", stringify!($ifield), self.$ifield), )*
+                ];
+                write!(f, "{} [{}]", stringify!($name), fields.join(", "))
+            }
+        }
+    };
+
+    // --- validation rules (kind-aware, comma-separated muncher) -----------
+    (@checks $kind:ident $field:ident, ) => {};
+    (@checks string $field:ident, not_empty $(, $($rest:tt)*)?) => {
+        $crate::assert_argument_not_empty!($field, stringify!($field))?;
+        $crate::entity!(@checks string $field, $($($rest)*)?);
+    };
+    (@checks string $field:ident, max_len = $max:literal $(, $($rest:tt)*)?) => {
+        $crate::assert_argument_length!($field, $max, stringify!($field))?;
+        $crate::entity!(@checks string $field, $($($rest)*)?);
+    };
+    (@checks opt_string $field:ident, not_empty $(, $($rest:tt)*)?) => {
+        if let Some(value) = $field {
+            $crate::assert_argument_not_empty!(value, stringify!($field))?;
+        }
+        $crate::entity!(@checks opt_string $field, $($($rest)*)?);
+    };
+    (@checks opt_string $field:ident, max_len = $max:literal $(, $($rest:tt)*)?) => {
+        if let Some(value) = $field {
+            $crate::assert_argument_length!(value, $max, stringify!($field))?;
+        }
+        $crate::entity!(@checks opt_string $field, $($($rest)*)?);
+    };
+
+    // --- constructor parameter types --------------------------------------
+    (@param_ty opt_string $ty:ty) => { ::std::option::Option<&str> };
+    (@param_ty string $ty:ty) => { &str };
+    (@param_ty bool $ty:ty) => { bool };
+    (@param_ty other $ty:ty) => { &$ty };
+
+    // --- field initialisation ---------------------------------------------
+    (@store opt_string $field:ident) => { $field.filter(|s| !s.is_empty()).map(|s| s.to_string()) };
+    (@store string $field:ident) => { $field.to_string() };
+    (@store bool $field:ident) => { $field };
+    (@store other $field:ident) => { $field.clone() };
+
+    // --- getter return types ----------------------------------------------
+    (@getter_ty opt_string $ty:ty) => { ::std::option::Option<&str> };
+    (@getter_ty string $ty:ty) => { &str };
+    (@getter_ty bool $ty:ty) => { bool };
+    (@getter_ty other $ty:ty) => { &$ty };
+
+    // --- getter bodies ----------------------------------------------------
+    (@getter opt_string $self:tt, $field:ident) => { $self.$field.as_deref() };
+    (@getter string $self:tt, $field:ident) => { &$self.$field };
+    (@getter bool $self:tt, $field:ident) => { $self.$field };
+    (@getter other $self:tt, $field:ident) => { &$self.$field };
+}
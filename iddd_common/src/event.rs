@@ -0,0 +1,119 @@
+use std::cell::RefCell;
+
+use chrono::{DateTime, Utc};
+
+/// A domain event: something that happened in the domain that the rest of the
+/// model cares about. Every event records when it occurred and the version of
+/// its schema, so stored events can be replayed and evolved.
+pub trait DomainEvent {
+    /// The instant at which the event occurred.
+    fn occurred_on(&self) -> DateTime<Utc>;
+
+    /// The schema version of the event.
+    fn event_version(&self) -> i32;
+}
+
+/// A handler invoked for every published [DomainEvent].
+type DomainEventHandler = Box<dyn Fn(&dyn DomainEvent)>;
+
+/// Thread-local publisher mediating between the aggregates that raise domain
+/// events and the handlers interested in them. Each thread has its own set of
+/// subscribers; an event reaches only the handlers subscribed on the thread
+/// that publishes it.
+pub struct DomainEventPublisher {
+    subscribers: Vec<DomainEventHandler>,
+}
+
+impl DomainEventPublisher {
+    const fn new() -> Self {
+        DomainEventPublisher { subscribers: Vec::new() }
+    }
+
+    /// Registers a handler invoked for every subsequently published event.
+    pub fn subscribe<H>(handler: H)
+    where
+        H: Fn(&dyn DomainEvent) + 'static,
+    {
+        INSTANCE.with(|publisher| publisher.borrow_mut().subscribers.push(Box::new(handler)));
+    }
+
+    /// Publishes an event to every registered subscriber.
+    pub fn publish<E: DomainEvent>(event: &E) {
+        INSTANCE.with(|publisher| {
+            for subscriber in publisher.borrow().subscribers.iter() {
+                subscriber(event);
+            }
+        });
+    }
+
+    /// Removes every registered subscriber.
+    pub fn reset() {
+        INSTANCE.with(|publisher| publisher.borrow_mut().subscribers.clear());
+    }
+}
+
+thread_local! {
+    static INSTANCE: RefCell<DomainEventPublisher> = const { RefCell::new(DomainEventPublisher::new()) };
+}
+
+/// Generates a domain-event struct carrying `occurred_on` and `event_version`
+/// alongside the supplied payload fields, a getter for each field, and a
+/// [DomainEvent](crate::event::DomainEvent) implementation exposing the
+/// timestamp and version.
+///
+/// `new(...)` stamps the event with the current time; `from_stored(...)`
+/// accepts an explicit timestamp for event replay.
+///
+/// ```ignore
+/// domain_event!(TenantActivated, version = 1 { tenant_id: TenantId });
+/// ```
+#[macro_export]
+macro_rules! domain_event {
+    ($name:ident, version = $version:literal { $( $field:ident : $ty:ty ),* $(,)? }) => {
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            occurred_on: ::chrono::DateTime<::chrono::Utc>,
+            event_version: i32,
+            $( $field: $ty, )*
+        }
+
+        impl $name {
+            /// Creates the event stamped with the current time.
+            pub fn new( $( $field: $ty, )* ) -> Self {
+                $name {
+                    occurred_on: ::chrono::Utc::now(),
+                    event_version: $version,
+                    $( $field, )*
+                }
+            }
+
+            /// Reconstructs the event with an explicit timestamp, for replay.
+            pub fn from_stored(
+                occurred_on: ::chrono::DateTime<::chrono::Utc>,
+                $( $field: $ty, )*
+            ) -> Self {
+                $name {
+                    occurred_on,
+                    event_version: $version,
+                    $( $field, )*
+                }
+            }
+
+            $(
+                pub fn $field(&self) -> &$ty {
+                    &self.$field
+                }
+            )*
+        }
+
+        impl $crate::event::DomainEvent for $name {
+            fn occurred_on(&self) -> ::chrono::DateTime<::chrono::Utc> {
+                self.occurred_on
+            }
+
+            fn event_version(&self) -> i32 {
+                self.event_version
+            }
+        }
+    };
+}
@@ -0,0 +1,4 @@
+pub mod assertion;
+pub mod entity;
+pub mod event;
+pub mod value_object;
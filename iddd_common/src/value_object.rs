@@ -0,0 +1,70 @@
+/// Generates a validated newtype value object wrapping a [String].
+///
+/// The spec names the type, an optional `uuid` flag, the allowed length range
+/// and the field name used in the generated error messages, e.g.
+///
+/// ```ignore
+/// value_object!(TenantId { len: 36..=36, uuid: true, field: "value" });
+/// value_object!(PostalCode { len: 1..=10, field: "value" });
+/// ```
+///
+/// Each invocation emits the `struct $name(String)`, a `new(&str)` constructor
+/// running `assert_argument_not_empty!` / `assert_argument_length!` (and
+/// `Uuid::parse_str` when `uuid` is set), a `value(&self)` accessor, a
+/// [Display](std::fmt::Display) implementation and the usual value-object
+/// derives. When `uuid` is set a `random()` constructor is emitted as well.
+#[macro_export]
+macro_rules! value_object {
+    ($name:ident { uuid: true, len: $min:literal ..= $max:literal, field: $field:literal }) => {
+        $crate::value_object!(@emit $name, $min, $max, $field, uuid);
+    };
+    ($name:ident { len: $min:literal ..= $max:literal, uuid: true, field: $field:literal }) => {
+        $crate::value_object!(@emit $name, $min, $max, $field, uuid);
+    };
+    ($name:ident { len: $min:literal ..= $max:literal, field: $field:literal }) => {
+        $crate::value_object!(@emit $name, $min, $max, $field, no_uuid);
+    };
+
+    (@emit $name:ident, $min:literal, $max:literal, $field:literal, $uuid:ident) => {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Creates a new value object, validating the supplied value.
+            pub fn new(value: &str) -> ::std::result::Result<Self, $crate::assertion::ArgumentError> {
+                $crate::assert_argument_not_empty!(value, $field)?;
+                $crate::assert_argument_length!(value, $min, $max, $field)?;
+                $crate::value_object!(@uuid_check value, $field, $uuid);
+                Ok($name(value.to_string()))
+            }
+
+            $crate::value_object!(@random $name, $uuid);
+
+            /// Returns the wrapped value.
+            pub fn value(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+
+    (@uuid_check $value:ident, $field:literal, uuid) => {
+        if ::uuid::Uuid::parse_str($value).is_err() {
+            return $crate::arg_error!("The {} has an invalid format.", $field);
+        }
+    };
+    (@uuid_check $value:ident, $field:literal, no_uuid) => {};
+
+    (@random $name:ident, uuid) => {
+        /// Generates a new random value object.
+        pub fn random() -> Self {
+            $name(::uuid::Uuid::new_v4().to_string())
+        }
+    };
+    (@random $name:ident, no_uuid) => {};
+}
@@ -1,7 +1,9 @@
-pub use invitation::Invitation;
+pub use events::{TenantActivated, TenantAdministratorInvited, TenantDeactivated};
+pub use invitation::{Invitation, InvitationDescriptor};
 pub use tenant::Tenant;
 pub use tenant_id::TenantId;
 
 mod tenant_id;
 mod tenant;
 mod invitation;
+mod events;
@@ -0,0 +1,7 @@
+use iddd_common::domain_event;
+
+use crate::domain::identity::tenant::TenantId;
+
+domain_event!(TenantActivated, version = 1 { tenant_id: TenantId });
+domain_event!(TenantDeactivated, version = 1 { tenant_id: TenantId });
+domain_event!(TenantAdministratorInvited, version = 1 { tenant_id: TenantId, description: String });
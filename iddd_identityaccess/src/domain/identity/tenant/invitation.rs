@@ -3,7 +3,7 @@ use std::fmt::Formatter;
 
 use chrono::prelude::*;
 
-use iddd_common::{arg_error, assert_argument_length, assert_argument_not_empty, assert_true};
+use iddd_common::{assert_argument_length, assert_argument_not_empty, assert_true};
 use iddd_common::assertion::ArgumentError;
 
 /// Invitation structure.
@@ -1,64 +1,90 @@
-use iddd_common::{arg_error, assert_argument_length, assert_argument_not_empty};
-use iddd_common::assertion::ArgumentError;
-use crate::domain::identity::tenant::{Invitation, TenantId};
-
-#[derive(Debug)]
-pub struct Tenant {
-    tenant_id: TenantId,
-    name: String,
-    description: Option<String>,
-    active: bool,
-    invitations: Vec<Invitation>,
-}
+use chrono::prelude::*;
+use uuid::Uuid;
+
+use iddd_common::{assert_state, entity};
+use iddd_common::assertion::{ArgumentError, StateError};
+use iddd_common::event::DomainEventPublisher;
+use crate::domain::identity::tenant::{Invitation, InvitationDescriptor, TenantId};
+use crate::domain::identity::tenant::{TenantActivated, TenantAdministratorInvited, TenantDeactivated};
+
+entity!(Tenant {
+    pub tenant_id: TenantId,
+    pub name: String [not_empty, max_len = 100],
+    pub description: Option<String> [max_len = 100],
+    pub active: bool
+} internal {
+    invitations: Vec<Invitation> = Vec::new()
+});
 
 impl Tenant {
-    /// Creates a new [Tenant] with supplied parameters.
-    ///
-    /// # Arguments
-    /// * `tenant_id` - The unique identifier of the tenant.
-    /// * `name` - The name of the tenant.
-    /// * `description` - The description of the tenant.
-    /// * `active` - Indicates whether the tenant is active or not.
-    pub fn new(tenant_id: &TenantId, name: &str, description: Option<&str>, active: bool) -> Result<Tenant, ArgumentError> {
-        assert_argument_not_empty!(name, "name")?;
-        assert_argument_length!(name, 100, "name")?;
-        if let Some(description) = description {
-            assert_argument_length!(description, 100, "description")?;
-        }
+    /// Activates the tenant, raising a [TenantActivated] event.
+    pub fn activate(&mut self) {
+        self.active = true;
+        DomainEventPublisher::publish(&TenantActivated::new(self.tenant_id.clone()));
+    }
 
-        Ok(Tenant {
-            tenant_id: tenant_id.clone(),
-            name: name.to_string(),
-            description: description.filter(|s| !s.is_empty()).map(|s| s.to_string()),
-            active,
-            invitations: Vec::new(),
-        })
+    /// Deactivates the tenant, raising a [TenantDeactivated] event.
+    pub fn deactivate(&mut self) {
+        self.active = false;
+        DomainEventPublisher::publish(&TenantDeactivated::new(self.tenant_id.clone()));
     }
 
-    pub fn tenant_id(&self) -> &TenantId {
-        return &self.tenant_id
+    /// Offers a new invitation with the supplied description.
+    ///
+    /// Only an active tenant may extend invitations. A
+    /// [TenantAdministratorInvited] event is raised for the offered invitation.
+    ///
+    /// # Arguments
+    /// * `description` - The description identifying the invitation.
+    pub fn offer_invitation(&mut self, description: &str) -> Result<&Invitation, StateError> {
+        assert_state!(self.active, "The tenant is not active")?;
+        let invitation = Invitation::new(Uuid::new_v4().to_string().as_str(), description)
+            .map_err(|e| StateError(e.0))?;
+        self.invitations.push(invitation);
+        DomainEventPublisher::publish(&TenantAdministratorInvited::new(self.tenant_id.clone(), description.to_string()));
+        Ok(self.invitations.last().unwrap())
     }
 
-    pub fn name(&self) -> &str {
-        return &self.name
+    /// Redefines the validity range of the invitation resolved by `identifier`.
+    ///
+    /// # Arguments
+    /// * `identifier` - The invitation id or description.
+    /// * `starting_on` - The start of invitation validity.
+    /// * `until` - The end of invitation validity.
+    pub fn redefine_invitation_as(&mut self, identifier: &str, starting_on: DateTime<Utc>, until: DateTime<Utc>) -> Result<(), ArgumentError> {
+        if let Some(invitation) = self.invitation_of(identifier) {
+            invitation.redefine_as(starting_on, until)?;
+        }
+        Ok(())
     }
 
-    pub fn description(&self) -> Option<&str> {
-        return self.description.as_deref()
+    /// Withdraws the invitation resolved by `identifier`, if any.
+    ///
+    /// # Arguments
+    /// * `identifier` - The invitation id or description.
+    pub fn withdraw_invitation(&mut self, identifier: &str) {
+        self.invitations.retain(|invitation| !invitation.is_identified_by(identifier));
     }
 
-    pub fn active(&self) -> bool {
-        return self.active
+    /// Checks whether the invitation resolved by `identifier` is available.
+    ///
+    /// # Arguments
+    /// * `identifier` - The invitation id or description.
+    pub fn is_invitation_available(&self, identifier: &str) -> bool {
+        self.invitations.iter()
+            .any(|invitation| invitation.is_identified_by(identifier) && invitation.is_available())
     }
 
-    /// Activates the tenant.
-    pub fn activate(&mut self) {
-        self.active = true;
+    /// Returns all the invitations that are currently available.
+    pub fn all_available_invitations(&self) -> Vec<&dyn InvitationDescriptor> {
+        self.invitations.iter()
+            .filter(|invitation| invitation.is_available())
+            .map(|invitation| invitation as &dyn InvitationDescriptor)
+            .collect()
     }
 
-    /// Deactivates the tenant.
-    pub fn deactivate(&mut self) {
-        self.active = false;
+    fn invitation_of(&mut self, identifier: &str) -> Option<&mut Invitation> {
+        self.invitations.iter_mut().find(|invitation| invitation.is_identified_by(identifier))
     }
 }
 
@@ -123,4 +149,41 @@ mod tests {
         tenant.deactivate();
         assert_eq!(false, tenant.active());
     }
+
+    #[test]
+    pub fn test_offer_invitation_on_inactive_tenant() {
+        let mut tenant = Tenant::new(&TenantId::random(), "name", None, false).unwrap();
+
+        let result = tenant.offer_invitation("aDescription");
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "The tenant is not active");
+    }
+
+    #[test]
+    pub fn test_offer_invitation() {
+        let mut tenant = Tenant::new(&TenantId::random(), "name", None, true).unwrap();
+
+        let result = tenant.offer_invitation("aDescription");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().description(), "aDescription");
+        assert_eq!(true, tenant.is_invitation_available("aDescription"));
+    }
+
+    #[test]
+    pub fn test_withdraw_invitation() {
+        let mut tenant = Tenant::new(&TenantId::random(), "name", None, true).unwrap();
+        tenant.offer_invitation("aDescription").unwrap();
+
+        tenant.withdraw_invitation("aDescription");
+        assert_eq!(false, tenant.is_invitation_available("aDescription"));
+    }
+
+    #[test]
+    pub fn test_all_available_invitations() {
+        let mut tenant = Tenant::new(&TenantId::random(), "name", None, true).unwrap();
+        tenant.offer_invitation("aDescription").unwrap();
+        tenant.offer_invitation("anotherDescription").unwrap();
+
+        assert_eq!(2, tenant.all_available_invitations().len());
+    }
 }
\ No newline at end of file